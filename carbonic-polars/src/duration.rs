@@ -0,0 +1,197 @@
+/// Polars-style duration strings and calendar-aware arithmetic on them.
+///
+/// A month isn't a fixed span of time (28-31 days) and a day isn't either
+/// once DST is involved, so a `Duration` keeps `months` and `days` apart
+/// from the fixed-length `nanoseconds` remainder instead of folding
+/// everything into one duration of nanoseconds the way `humanize_duration_str`
+/// does today.
+use chrono::{Datelike, NaiveDate, Timelike};
+use pyo3_polars::export::polars_core::prelude::PolarsError;
+
+use crate::utils::from_timestamp_micros;
+
+/// A parsed duration: `months` and `days` are calendar quantities,
+/// `nanoseconds` is a fixed-length remainder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Duration {
+    pub months: i64,
+    pub days: i64,
+    pub nanoseconds: i64,
+    /// `false` once the string contains a calendar-relative unit
+    /// (`mo`, `q`, `y`), even if that component's amount is zero.
+    pub is_constant: bool,
+}
+
+/// Parse a duration string made of `<amount><unit>` pairs, e.g.
+/// `"1y2mo3d4h30m"` or `"-15us"`. Supported units: `ns, us, ms, s, m, h,
+/// d, w, mo, q, y` (`w` = 7d, `q` = 3mo). A leading `-` negates every
+/// component.
+pub fn parse_duration(input: &str) -> Result<Duration, PolarsError> {
+    let invalid = || PolarsError::ComputeError(
+        format!("Invalid duration string: '{}'", input).into()
+    );
+
+    let mut chars = input.chars().peekable();
+    let negative = chars.peek() == Some(&'-');
+    if negative {
+        chars.next();
+    }
+
+    let mut duration = Duration { is_constant: true, ..Default::default() };
+    let mut saw_component = false;
+
+    loop {
+        let mut digits = String::new();
+        while matches!(chars.peek(), Some(c) if c.is_ascii_digit()) {
+            digits.push(chars.next().unwrap());
+        }
+        if digits.is_empty() {
+            break;
+        }
+        let amount: i64 = digits.parse().map_err(|_| invalid())?;
+
+        let mut unit = String::new();
+        while matches!(chars.peek(), Some(c) if c.is_alphabetic()) {
+            unit.push(chars.next().unwrap());
+        }
+
+        match unit.as_str() {
+            "ns" => duration.nanoseconds += amount,
+            "us" => duration.nanoseconds += amount * 1_000,
+            "ms" => duration.nanoseconds += amount * 1_000_000,
+            "s" => duration.nanoseconds += amount * 1_000_000_000,
+            "m" => duration.nanoseconds += amount * 60_000_000_000,
+            "h" => duration.nanoseconds += amount * 3_600_000_000_000,
+            "d" => duration.days += amount,
+            "w" => duration.days += amount * 7,
+            "mo" => { duration.months += amount; duration.is_constant = false; }
+            "q" => { duration.months += amount * 3; duration.is_constant = false; }
+            "y" => { duration.months += amount * 12; duration.is_constant = false; }
+            _ => return Err(invalid()),
+        }
+
+        saw_component = true;
+    }
+
+    if !saw_component || chars.peek().is_some() {
+        return Err(invalid());
+    }
+
+    if negative {
+        duration.months = -duration.months;
+        duration.days = -duration.days;
+        duration.nanoseconds = -duration.nanoseconds;
+    }
+
+    Ok(duration)
+}
+
+/// Apply a duration to a microsecond timestamp: months first (real
+/// calendar arithmetic, clamping day-of-month to the target month's
+/// length), then days, then the nanosecond remainder.
+pub fn add_to_timestamp(timestamp_micros: i64, duration: &Duration) -> Result<i64, PolarsError> {
+    let datetime = from_timestamp_micros(timestamp_micros)?;
+
+    let datetime = if duration.months != 0 {
+        add_calendar_months(datetime, duration.months)?
+    } else {
+        datetime
+    };
+
+    let datetime = datetime + chrono::Duration::days(duration.days);
+    let datetime = datetime + chrono::Duration::nanoseconds(duration.nanoseconds);
+
+    Ok(datetime.timestamp_micros())
+}
+
+/// Add whole calendar months to a datetime, clamping the day-of-month
+/// when the target month is shorter (e.g. Jan 31 + 1mo -> Feb 28/29).
+fn add_calendar_months(
+    datetime: chrono::DateTime<chrono::Utc>,
+    months: i64,
+) -> Result<chrono::DateTime<chrono::Utc>, PolarsError> {
+    let invalid = || PolarsError::ComputeError("Invalid datetime after month arithmetic".into());
+
+    let total_months = datetime.year() as i64 * 12 + (datetime.month() as i64 - 1) + months;
+    let year = total_months.div_euclid(12) as i32;
+    let month = (total_months.rem_euclid(12) + 1) as u32;
+    let day = datetime.day().min(days_in_month(year, month));
+
+    NaiveDate::from_ymd_opt(year, month, day)
+        .and_then(|d| d.and_hms_micro_opt(
+            datetime.hour(),
+            datetime.minute(),
+            datetime.second(),
+            datetime.timestamp_subsec_micros(),
+        ))
+        .map(|naive| naive.and_utc())
+        .ok_or_else(invalid)
+}
+
+/// Number of days in the given calendar month
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let first_of_next = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    }.expect("month overflow produced an invalid date");
+
+    let first_of_this = NaiveDate::from_ymd_opt(year, month, 1)
+        .expect("month is always 1-12");
+
+    first_of_next.signed_duration_since(first_of_this).num_days() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Datelike;
+
+    #[test]
+    fn test_parse_duration_mixed_units() {
+        let d = parse_duration("1y2mo3d4h30m").unwrap();
+        assert_eq!(d.months, 12 + 2);
+        assert_eq!(d.days, 3);
+        assert_eq!(d.nanoseconds, 4 * 3_600_000_000_000 + 30 * 60_000_000_000);
+        assert!(!d.is_constant);
+    }
+
+    #[test]
+    fn test_parse_duration_negative_constant() {
+        let d = parse_duration("-15us").unwrap();
+        assert_eq!(d.months, 0);
+        assert_eq!(d.days, 0);
+        assert_eq!(d.nanoseconds, -15_000);
+        assert!(d.is_constant);
+    }
+
+    #[test]
+    fn test_parse_duration_week_and_quarter() {
+        let d = parse_duration("2w1q").unwrap();
+        assert_eq!(d.days, 14);
+        assert_eq!(d.months, 3);
+        assert!(!d.is_constant);
+    }
+
+    #[test]
+    fn test_parse_duration_rejects_unknown_unit() {
+        assert!(parse_duration("5x").is_err());
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("abc").is_err());
+    }
+
+    #[test]
+    fn test_add_to_timestamp_clamps_day_of_month() {
+        // 2024-01-31 + 1mo -> 2024-02-29 (leap year), not Mar 2
+        let ts = to_timestamp_micros_for_test(2024, 1, 31, 0, 0, 0, 0);
+        let duration = parse_duration("1mo").unwrap();
+        let result = add_to_timestamp(ts, &duration).unwrap();
+        let dt = from_timestamp_micros(result).unwrap();
+
+        assert_eq!((dt.year(), dt.month(), dt.day()), (2024, 2, 29));
+    }
+
+    fn to_timestamp_micros_for_test(y: i32, mo: u32, d: u32, h: u32, mi: u32, s: u32, us: u32) -> i64 {
+        crate::utils::to_timestamp_micros(y, mo, d, h, mi, s, us).unwrap()
+    }
+}
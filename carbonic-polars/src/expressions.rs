@@ -4,7 +4,7 @@ use serde::Deserialize;
 
 use crate::parsing::{parse_carbonic_format, parse_iso_8601};
 use crate::business_days::{add_bdays, subtract_bdays};
-use crate::formatting::{format_with_locale, humanize_duration_str};
+use crate::formatting::{format_with_locale, humanize_duration_str, parse_format_tokens, TimeZone};
 
 #[derive(Deserialize)]
 struct ParseFormatKwargs {
@@ -23,6 +23,7 @@ struct BusinessDayKwargs {
 struct FormatKwargs {
     format: String,
     locale: Option<String>,
+    tz: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -114,13 +115,17 @@ fn subtract_business_days(inputs: &[Series], kwargs: BusinessDayKwargs) -> Polar
 #[polars_expr(output_type=String)]
 fn format_localized(inputs: &[Series], kwargs: FormatKwargs) -> PolarsResult<Series> {
     let ca = inputs[0].datetime()?;
-    let format = kwargs.format;
     let locale = kwargs.locale.unwrap_or_else(|| "en".to_string());
 
+    // Compile the format string once per expression instead of re-parsing
+    // it for every row in the column.
+    let items = parse_format_tokens(&kwargs.format)?;
+    let tz = kwargs.tz.as_deref().map(TimeZone::parse).transpose()?;
+
     let out: StringChunked = ca.apply_into_string_amortized(|timestamp_opt, output| {
         match timestamp_opt {
             Some(timestamp) => {
-                format_with_locale(timestamp, &format, &locale, output)
+                format_with_locale(timestamp, &items, &locale, tz.as_ref(), output)
             },
             None => output.push_str(""),
         }
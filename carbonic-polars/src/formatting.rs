@@ -1,6 +1,8 @@
-use chrono::{DateTime, Utc, Timelike, Datelike};
+use chrono::{DateTime, FixedOffset, Offset, Utc, Timelike, Datelike};
+use std::str::FromStr;
 use std::collections::HashMap;
 use once_cell::sync::Lazy;
+use pyo3_polars::export::polars_core::prelude::PolarsError;
 
 /// Localized month names
 static MONTH_NAMES: Lazy<HashMap<&'static str, [&'static str; 12]>> = Lazy::new(|| {
@@ -66,11 +68,281 @@ static SHORT_DAY_NAMES: Lazy<HashMap<&'static str, [&'static str; 7]>> = Lazy::n
     map
 });
 
-/// Format timestamp with localized output
+/// A single piece of a precompiled Carbonic format string.
+///
+/// Parsing a format string into a `Vec<FormatItem>` once per expression
+/// (instead of re-scanning the raw string for every row) is what lets
+/// [`format_with_locale`] walk a flat vector in the hot loop rather than
+/// allocating and re-deriving tokens per timestamp.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormatItem {
+    /// Literal text copied verbatim, including escaped characters.
+    Literal(String),
+    Year4 { pad: Pad },
+    Year2 { pad: Pad },
+    Month { pad: Pad },
+    MonthName { short: bool },
+    Day { pad: Pad },
+    DayName { short: bool },
+    Hour24 { pad: Pad },
+    Hour12 { pad: Pad },
+    Minute,
+    Second,
+    Micros,
+    Millis,
+    AmPm { upper: bool },
+    /// `O`: `±hhmm`
+    TzOffsetNoColon,
+    /// `P`: `±hh:mm`
+    TzOffsetColon,
+    /// `T`: zone abbreviation, or the offset when the zone has no name
+    TzName,
+    Iso8601,
+    Rfc2822,
+    /// `W`: ISO-8601 week number (01-53)
+    IsoWeek { pad: Pad },
+    /// `o`: ISO-8601 week-year, which can differ from the calendar year
+    /// for dates in the first/last days of January/December.
+    IsoWeekYear { pad: Pad },
+    /// `N`/`w`: ISO-8601 day-of-week number, Monday = 1 ... Sunday = 7.
+    IsoWeekday,
+    /// A token wrapped in a `{width}{align}{token}` directive, e.g. `{6>Y}`.
+    Aligned { inner: Box<FormatItem>, width: usize, align: Align },
+}
+
+/// How a numeric field is padded to its natural width.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pad {
+    /// Zero-padded, e.g. `Y` -> `"2023"`, `m` -> `"04"`.
+    Zero,
+    /// Space-padded, requested with the `%_` prefix, e.g. `%_m` -> `" 4"`.
+    Space,
+    /// No padding, e.g. `n`/`j`/`G`/`g`, or requested with `%-`.
+    None,
+}
+
+/// Justification for a `{width}{align}{token}` directive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+/// Target timezone to render a UTC timestamp in: either a named IANA
+/// zone (`Europe/Warsaw`) or a fixed offset in minutes from UTC.
+#[derive(Debug, Clone)]
+pub enum TimeZone {
+    Named(chrono_tz::Tz),
+    FixedOffsetMinutes(i32),
+}
+
+impl TimeZone {
+    /// Parse an IANA zone name or a signed offset in minutes (e.g.
+    /// `"Europe/Warsaw"` or `"-330"`) into a [`TimeZone`].
+    pub fn parse(spec: &str) -> Result<Self, PolarsError> {
+        if let Ok(minutes) = spec.parse::<i32>() {
+            return Ok(TimeZone::FixedOffsetMinutes(minutes));
+        }
+
+        chrono_tz::Tz::from_str(spec)
+            .map(TimeZone::Named)
+            .map_err(|_| PolarsError::ComputeError(
+                format!("Unknown timezone: '{}'", spec).into()
+            ))
+    }
+}
+
+/// Map a bare token character to its default-padding `FormatItem`.
+///
+/// This is the single place that decides which characters are valid
+/// format tokens; [`parse_format_tokens`] rejects anything that isn't
+/// handled here, so validation and parsing can't drift apart, and the
+/// `{width}{align}{token}` directive reuses it instead of re-deriving its
+/// own token table. The `%-`/`%_` directives go through [`with_pad`],
+/// which accepts only the numeric tokens that have a padding.
+fn base_token(ch: char) -> Option<FormatItem> {
+    Some(match ch {
+        'Y' => FormatItem::Year4 { pad: Pad::Zero },
+        'y' => FormatItem::Year2 { pad: Pad::Zero },
+
+        'm' => FormatItem::Month { pad: Pad::Zero },
+        'n' => FormatItem::Month { pad: Pad::None },
+        'F' => FormatItem::MonthName { short: false },
+        'M' => FormatItem::MonthName { short: true },
+
+        'd' => FormatItem::Day { pad: Pad::Zero },
+        'j' => FormatItem::Day { pad: Pad::None },
+        'l' => FormatItem::DayName { short: false },
+        'D' => FormatItem::DayName { short: true },
+
+        'H' => FormatItem::Hour24 { pad: Pad::Zero },
+        'G' => FormatItem::Hour24 { pad: Pad::None },
+        'h' => FormatItem::Hour12 { pad: Pad::Zero },
+        'g' => FormatItem::Hour12 { pad: Pad::None },
+
+        'i' => FormatItem::Minute,
+        's' => FormatItem::Second,
+
+        'u' => FormatItem::Micros,
+        'v' => FormatItem::Millis,
+
+        'A' => FormatItem::AmPm { upper: true },
+        'a' => FormatItem::AmPm { upper: false },
+
+        'c' => FormatItem::Iso8601,
+        'r' => FormatItem::Rfc2822,
+
+        'O' => FormatItem::TzOffsetNoColon,
+        'P' => FormatItem::TzOffsetColon,
+        'T' => FormatItem::TzName,
+
+        'W' => FormatItem::IsoWeek { pad: Pad::Zero },
+        'o' => FormatItem::IsoWeekYear { pad: Pad::Zero },
+        'N' | 'w' => FormatItem::IsoWeekday,
+
+        _ => return None,
+    })
+}
+
+/// Re-pad a token that supports `%-`/`%_` width overrides, or `None` if
+/// the token has no concept of padding (e.g. `F`, `i`, `c`).
+fn with_pad(ch: char, pad: Pad) -> Option<FormatItem> {
+    match ch {
+        'Y' => Some(FormatItem::Year4 { pad }),
+        'y' => Some(FormatItem::Year2 { pad }),
+        'm' | 'n' => Some(FormatItem::Month { pad }),
+        'd' | 'j' => Some(FormatItem::Day { pad }),
+        'H' | 'G' => Some(FormatItem::Hour24 { pad }),
+        'h' | 'g' => Some(FormatItem::Hour12 { pad }),
+        'W' => Some(FormatItem::IsoWeek { pad }),
+        'o' => Some(FormatItem::IsoWeekYear { pad }),
+        _ => None,
+    }
+}
+
+/// Parse a Carbonic format string into a reusable token AST.
+///
+/// Beyond the bare single-character tokens, this understands two width
+/// directives: `%-d`/`%_H` override a token's own padding (no-pad / space-pad
+/// respectively), and `{width}{align}{token}` (e.g. `{6>Y}`, `{3<j}`, `{4^H}`)
+/// justifies the rendered field within a fixed column.
+pub fn parse_format_tokens(format: &str) -> Result<Vec<FormatItem>, PolarsError> {
+    let mut items = Vec::new();
+    let mut literal = String::new();
+    let mut chars = format.chars().peekable();
+
+    macro_rules! push_token {
+        ($item:expr) => {{
+            if !literal.is_empty() {
+                items.push(FormatItem::Literal(std::mem::take(&mut literal)));
+            }
+            items.push($item);
+        }};
+    }
+
+    let invalid = |msg: String| PolarsError::ComputeError(msg.into());
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            // Escape sequences
+            '\\' => {
+                if let Some(next_ch) = chars.next() {
+                    literal.push(next_ch);
+                } else {
+                    literal.push('\\');
+                }
+            }
+
+            // `%-d` (no-pad) / `%_H` (space-pad) width overrides
+            '%' if matches!(chars.peek(), Some('-') | Some('_')) => {
+                let pad = if chars.next() == Some('-') { Pad::None } else { Pad::Space };
+                let tok_ch = chars.next().ok_or_else(|| invalid(
+                    format!("Format string '{}' ends with an incomplete '%' directive", format)
+                ))?;
+                let item = with_pad(tok_ch, pad).ok_or_else(|| invalid(
+                    format!("Token '{}' does not support %-/%_ padding", tok_ch)
+                ))?;
+                push_token!(item);
+            }
+
+            // `{width}{align}{token}`, e.g. `{6>Y}`
+            '{' => {
+                let mut spec = String::new();
+                let mut closed = false;
+                for next_ch in chars.by_ref() {
+                    if next_ch == '}' {
+                        closed = true;
+                        break;
+                    }
+                    spec.push(next_ch);
+                }
+                if !closed {
+                    return Err(invalid(format!("Unterminated '{{' directive in format string '{}'", format)));
+                }
+                push_token!(parse_aligned_token(&spec)?);
+            }
+
+            // Unknown alphabetic token
+            _ if ch.is_alphabetic() => {
+                match base_token(ch) {
+                    Some(item) => push_token!(item),
+                    None => return Err(invalid(format!("Unsupported format token: '{}'", ch))),
+                }
+            }
+
+            // Literal character
+            _ => literal.push(ch),
+        }
+    }
+
+    if !literal.is_empty() {
+        items.push(FormatItem::Literal(literal));
+    }
+
+    Ok(items)
+}
+
+/// Parse the contents of a `{width}{align}{token}` directive (without the
+/// braces), e.g. `"6>Y"`.
+fn parse_aligned_token(spec: &str) -> Result<FormatItem, PolarsError> {
+    let invalid = || PolarsError::ComputeError(
+        format!("Invalid format directive '{{{}}}': expected '{{width}}{{align}}{{token}}' like '{{6>Y}}'", spec).into()
+    );
+
+    let mut chars = spec.chars().peekable();
+
+    let mut digits = String::new();
+    while matches!(chars.peek(), Some(c) if c.is_ascii_digit()) {
+        digits.push(chars.next().unwrap());
+    }
+    let width: usize = digits.parse().map_err(|_| invalid())?;
+
+    let align = match chars.next() {
+        Some('<') => Align::Left,
+        Some('>') => Align::Right,
+        Some('^') => Align::Center,
+        _ => return Err(invalid()),
+    };
+
+    let tok_ch = chars.next().ok_or_else(invalid)?;
+    if chars.next().is_some() {
+        return Err(invalid());
+    }
+
+    let inner = base_token(tok_ch).ok_or_else(invalid)?;
+    Ok(FormatItem::Aligned { inner: Box::new(inner), width, align })
+}
+
+/// Format timestamp with localized output using a precompiled token AST
+///
+/// `tz` selects the zone fields are rendered in; `None` keeps the
+/// timestamp in UTC, matching the crate's original behavior.
 pub fn format_with_locale(
     timestamp_micros: i64,
-    format: &str,
+    items: &[FormatItem],
     locale: &str,
+    tz: Option<&TimeZone>,
     output: &mut String
 ) {
     // Convert microseconds to DateTime
@@ -85,119 +357,230 @@ pub fn format_with_locale(
         }
     };
 
-    // Process format tokens
-    let result = process_format_tokens(datetime, format, locale);
-    output.push_str(&result);
+    let (local_datetime, tz_name) = resolve_timezone(datetime, tz);
+    render_format_items(local_datetime, items, locale, &tz_name, output);
 }
 
-/// Process Carbonic format tokens with localization
-fn process_format_tokens(datetime: DateTime<Utc>, format: &str, locale: &str) -> String {
-    let mut result = String::with_capacity(format.len() * 2);
-    let mut chars = format.chars().peekable();
+/// Convert a UTC instant into the target zone, returning the converted
+/// datetime (as a fixed offset, since that's all the format tokens need)
+/// alongside the zone abbreviation/offset `T` should render.
+fn resolve_timezone(datetime: DateTime<Utc>, tz: Option<&TimeZone>) -> (DateTime<FixedOffset>, String) {
+    match tz {
+        None => (datetime.fixed_offset(), "UTC".to_string()),
+        Some(TimeZone::FixedOffsetMinutes(minutes)) => {
+            let offset = FixedOffset::east_opt(minutes * 60)
+                .unwrap_or_else(|| FixedOffset::east_opt(0).unwrap());
+            (datetime.with_timezone(&offset), format_offset(offset, false))
+        }
+        Some(TimeZone::Named(zone)) => {
+            let local = datetime.with_timezone(zone);
+            let name = local.format("%Z").to_string();
+            (local.fixed_offset(), name)
+        }
+    }
+}
 
-    while let Some(ch) = chars.next() {
-        match ch {
-            // Escape sequences
-            '\\' => {
-                if let Some(&next_ch) = chars.peek() {
-                    chars.next();
-                    result.push(next_ch);
-                } else {
-                    result.push('\\');
-                }
-            }
-            // Year tokens
-            'Y' => result.push_str(&format!("{:04}", datetime.year())),
-            'y' => result.push_str(&format!("{:02}", datetime.year() % 100)),
-
-            // Month tokens
-            'm' => result.push_str(&format!("{:02}", datetime.month())),
-            'n' => result.push_str(&datetime.month().to_string()),
-            'F' => {
-                let month_name = get_month_name(datetime.month() as usize - 1, false, locale);
-                result.push_str(month_name);
-            }
-            'M' => {
-                let month_name = get_month_name(datetime.month() as usize - 1, true, locale);
-                result.push_str(month_name);
-            }
+/// Format a fixed offset as `±hhmm` (`colon = false`) or `±hh:mm`
+fn format_offset(offset: FixedOffset, colon: bool) -> String {
+    let total_minutes = offset.fix().local_minus_utc() / 60;
+    let sign = if total_minutes < 0 { '-' } else { '+' };
+    let hours = total_minutes.abs() / 60;
+    let minutes = total_minutes.abs() % 60;
 
-            // Day tokens
-            'd' => result.push_str(&format!("{:02}", datetime.day())),
-            'j' => result.push_str(&datetime.day().to_string()),
-            'l' => {
-                let day_name = get_day_name(datetime.weekday().num_days_from_monday() as usize, false, locale);
-                result.push_str(day_name);
-            }
-            'D' => {
-                let day_name = get_day_name(datetime.weekday().num_days_from_monday() as usize, true, locale);
-                result.push_str(day_name);
-            }
+    if colon {
+        format!("{}{:02}:{:02}", sign, hours, minutes)
+    } else {
+        format!("{}{:02}{:02}", sign, hours, minutes)
+    }
+}
 
-            // Hour tokens
-            'H' => result.push_str(&format!("{:02}", datetime.hour())),
-            'G' => result.push_str(&datetime.hour().to_string()),
-            'h' => {
-                let hour_12 = match datetime.hour() {
-                    0 => 12,
-                    h if h > 12 => h - 12,
-                    h => h,
-                };
-                result.push_str(&format!("{:02}", hour_12));
-            }
-            'g' => {
-                let hour_12 = match datetime.hour() {
-                    0 => 12,
-                    h if h > 12 => h - 12,
-                    h => h,
-                };
-                result.push_str(&hour_12.to_string());
-            }
+/// Walk a precompiled token AST, pushing each rendered field into `output`
+fn render_format_items(datetime: DateTime<FixedOffset>, items: &[FormatItem], locale: &str, tz_name: &str, output: &mut String) {
+    for item in items {
+        render_format_item(datetime, item, locale, tz_name, output);
+    }
+}
 
-            // Minute/Second tokens
-            'i' => result.push_str(&format!("{:02}", datetime.minute())),
-            's' => result.push_str(&format!("{:02}", datetime.second())),
+/// Render a single format item. Pulled out of [`render_format_items`] so
+/// `FormatItem::Aligned` can recurse into its wrapped token.
+fn render_format_item(datetime: DateTime<FixedOffset>, item: &FormatItem, locale: &str, tz_name: &str, output: &mut String) {
+    match item {
+        FormatItem::Literal(text) => output.push_str(text),
 
-            // Microsecond tokens
-            'u' => result.push_str(&format!("{:06}", datetime.timestamp_subsec_micros())),
-            'v' => result.push_str(&format!("{:03}", datetime.timestamp_subsec_millis())),
+        FormatItem::Year4 { pad } => output.push_str(&pad_number(datetime.year(), 4, *pad)),
+        FormatItem::Year2 { pad } => output.push_str(&pad_number(datetime.year() % 100, 2, *pad)),
 
-            // AM/PM tokens
-            'A' => result.push_str(if datetime.hour() < 12 { "AM" } else { "PM" }),
-            'a' => result.push_str(if datetime.hour() < 12 { "am" } else { "pm" }),
+        FormatItem::Month { pad } => output.push_str(&pad_number(datetime.month() as i32, 2, *pad)),
+        FormatItem::MonthName { short } => {
+            output.push_str(&get_month_name(datetime.month() as usize - 1, *short, locale));
+        }
 
-            // Special formats
-            'c' => result.push_str(&datetime.to_rfc3339()),
-            'r' => result.push_str(&datetime.format("%a, %d %b %Y %H:%M:%S %z").to_string()),
+        FormatItem::Day { pad } => output.push_str(&pad_number(datetime.day() as i32, 2, *pad)),
+        FormatItem::DayName { short } => {
+            output.push_str(&get_day_name(datetime.weekday().num_days_from_monday() as usize, *short, locale));
+        }
 
-            // Literal character
-            _ => result.push(ch),
+        FormatItem::Hour24 { pad } => output.push_str(&pad_number(datetime.hour() as i32, 2, *pad)),
+        FormatItem::Hour12 { pad } => output.push_str(&pad_number(to_hour_12(datetime.hour()) as i32, 2, *pad)),
+
+        FormatItem::Minute => output.push_str(&format!("{:02}", datetime.minute())),
+        FormatItem::Second => output.push_str(&format!("{:02}", datetime.second())),
+
+        FormatItem::Micros => output.push_str(&format!("{:06}", datetime.timestamp_subsec_micros())),
+        FormatItem::Millis => output.push_str(&format!("{:03}", datetime.timestamp_subsec_millis())),
+
+        FormatItem::AmPm { upper } => {
+            output.push_str(&get_am_pm(datetime.hour() >= 12, *upper, locale));
+        }
+
+        FormatItem::TzOffsetNoColon => output.push_str(&format_offset(*datetime.offset(), false)),
+        FormatItem::TzOffsetColon => output.push_str(&format_offset(*datetime.offset(), true)),
+        FormatItem::TzName => output.push_str(tz_name),
+
+        FormatItem::Iso8601 => output.push_str(&datetime.to_rfc3339()),
+        FormatItem::Rfc2822 => output.push_str(&datetime.format("%a, %d %b %Y %H:%M:%S %z").to_string()),
+
+        FormatItem::IsoWeek { pad } => output.push_str(&pad_number(datetime.iso_week().week() as i32, 2, *pad)),
+        FormatItem::IsoWeekYear { pad } => output.push_str(&pad_number(datetime.iso_week().year(), 4, *pad)),
+        FormatItem::IsoWeekday => output.push_str(&datetime.weekday().number_from_monday().to_string()),
+
+        FormatItem::Aligned { inner, width, align } => {
+            let mut rendered = String::new();
+            render_format_item(datetime, inner, locale, tz_name, &mut rendered);
+            output.push_str(&justify(&rendered, *width, *align));
+        }
+    }
+}
+
+/// Render a number with the requested padding, to its natural `width`.
+fn pad_number(value: i32, width: usize, pad: Pad) -> String {
+    match pad {
+        Pad::Zero => format!("{:0width$}", value, width = width),
+        Pad::Space => format!("{:width$}", value, width = width),
+        Pad::None => value.to_string(),
+    }
+}
+
+/// Pad `text` with spaces out to `width`, left/right/center justified.
+/// Text already at or past `width` is returned unchanged.
+fn justify(text: &str, width: usize, align: Align) -> String {
+    let len = text.chars().count();
+    if len >= width {
+        return text.to_string();
+    }
+
+    let padding = width - len;
+    match align {
+        Align::Left => format!("{}{}", text, " ".repeat(padding)),
+        Align::Right => format!("{}{}", " ".repeat(padding), text),
+        Align::Center => {
+            let left = padding / 2;
+            let right = padding - left;
+            format!("{}{}{}", " ".repeat(left), text, " ".repeat(right))
         }
     }
+}
 
-    result
+/// Convert a 24-hour value to its 12-hour clock equivalent
+fn to_hour_12(hour: u32) -> u32 {
+    match hour {
+        0 => 12,
+        h if h > 12 => h - 12,
+        h => h,
+    }
 }
 
 /// Get localized month name
-fn get_month_name(month_index: usize, short: bool, locale: &str) -> &'static str {
+///
+/// With the `icu` feature enabled, this queries CLDR data for the given
+/// BCP-47 locale first; the static `en`/`pl` tables are the fallback for
+/// locales ICU has no data for (and the only source when the feature is
+/// off).
+fn get_month_name(month_index: usize, short: bool, locale: &str) -> String {
+    #[cfg(feature = "icu")]
+    {
+        if let Some(name) = crate::icu_locale::month_name(locale, month_index, short) {
+            return name;
+        }
+    }
+
     let names = if short {
         SHORT_MONTH_NAMES.get(locale).unwrap_or(&SHORT_MONTH_NAMES["en"])
     } else {
         MONTH_NAMES.get(locale).unwrap_or(&MONTH_NAMES["en"])
     };
 
-    names.get(month_index).unwrap_or(&names[0])
+    names.get(month_index).unwrap_or(&names[0]).to_string()
 }
 
 /// Get localized day name
-fn get_day_name(day_index: usize, short: bool, locale: &str) -> &'static str {
+///
+/// See [`get_month_name`] for the ICU-vs-static-table fallback rule.
+fn get_day_name(day_index: usize, short: bool, locale: &str) -> String {
+    #[cfg(feature = "icu")]
+    {
+        if let Some(name) = crate::icu_locale::day_name(locale, day_index, short) {
+            return name;
+        }
+    }
+
     let names = if short {
         SHORT_DAY_NAMES.get(locale).unwrap_or(&SHORT_DAY_NAMES["en"])
     } else {
         DAY_NAMES.get(locale).unwrap_or(&DAY_NAMES["en"])
     };
 
-    names.get(day_index).unwrap_or(&names[0])
+    names.get(day_index).unwrap_or(&names[0]).to_string()
+}
+
+/// Get the localized AM/PM marker
+///
+/// See [`get_month_name`] for the ICU-vs-static-table fallback rule.
+fn get_am_pm(is_pm: bool, upper: bool, locale: &str) -> String {
+    #[cfg(feature = "icu")]
+    {
+        if let Some(marker) = crate::icu_locale::am_pm(locale, is_pm) {
+            return if upper { marker.to_uppercase() } else { marker.to_lowercase() };
+        }
+    }
+
+    match (is_pm, upper) {
+        (false, true) => "AM".to_string(),
+        (false, false) => "am".to_string(),
+        (true, true) => "PM".to_string(),
+        (true, false) => "pm".to_string(),
+    }
+}
+
+/// All 12 localized month names, for the reverse (string -> timestamp) parser
+pub(crate) fn month_name_candidates(locale: &str, short: bool) -> Vec<String> {
+    (0..12).map(|i| get_month_name(i, short, locale)).collect()
+}
+
+/// All 7 localized day names, for the reverse (string -> timestamp) parser
+pub(crate) fn day_name_candidates(locale: &str, short: bool) -> Vec<String> {
+    (0..7).map(|i| get_day_name(i, short, locale)).collect()
+}
+
+/// `(am, pm)` localized markers, for the reverse (string -> timestamp) parser
+pub(crate) fn am_pm_candidates(locale: &str) -> (String, String) {
+    (get_am_pm(false, true, locale), get_am_pm(true, true, locale))
+}
+
+/// Get the localized decimal separator
+///
+/// See [`get_month_name`] for the ICU-vs-static-table fallback rule; the
+/// non-ICU fallback only distinguishes Polish (comma) from everything
+/// else (period), matching the crate's original hardcoded behavior.
+fn get_decimal_separator(locale: &str) -> char {
+    #[cfg(feature = "icu")]
+    {
+        if let Some(sep) = crate::icu_locale::decimal_separator(locale) {
+            return sep;
+        }
+    }
+
+    if locale == "pl" { ',' } else { '.' }
 }
 
 /// Humanize duration with localization
@@ -244,11 +627,8 @@ pub fn humanize_duration_str(
             parts.push((seconds as i32, "second"));
         } else {
             // Handle fractional seconds
-            let formatted_seconds = if locale == "pl" {
-                format!("{:.3}", seconds).replace('.', ",")
-            } else {
-                format!("{:.3}", seconds)
-            };
+            let formatted_seconds = format!("{:.3}", seconds)
+                .replacen('.', &get_decimal_separator(locale).to_string(), 1);
             output.push_str(&format!("{}{} {}",
                 if is_negative { "-" } else { "" },
                 formatted_seconds,
@@ -331,8 +711,9 @@ mod tests {
         // 2023-12-25 14:30:15 UTC
         let timestamp = 1703516215_000_000i64; // microseconds
         let mut output = String::new();
+        let items = parse_format_tokens("F j, Y").unwrap();
 
-        format_with_locale(timestamp, "F j, Y", "en", &mut output);
+        format_with_locale(timestamp, &items, "en", None, &mut output);
         assert_eq!(output, "December 25, 2023");
     }
 
@@ -341,11 +722,66 @@ mod tests {
         // 2023-12-25 14:30:15 UTC
         let timestamp = 1703516215_000_000i64; // microseconds
         let mut output = String::new();
+        let items = parse_format_tokens("F j, Y").unwrap();
 
-        format_with_locale(timestamp, "F j, Y", "pl", &mut output);
+        format_with_locale(timestamp, &items, "pl", None, &mut output);
         assert_eq!(output, "grudzień 25, 2023");
     }
 
+    #[test]
+    fn test_parse_format_tokens_folds_literals() {
+        let items = parse_format_tokens("Y-m-d").unwrap();
+        assert_eq!(items, vec![
+            FormatItem::Year4 { pad: Pad::Zero },
+            FormatItem::Literal("-".to_string()),
+            FormatItem::Month { pad: Pad::Zero },
+            FormatItem::Literal("-".to_string()),
+            FormatItem::Day { pad: Pad::Zero },
+        ]);
+    }
+
+    #[test]
+    fn test_percent_width_modifiers() {
+        // 2023-01-05 UTC -> month/day single-digit, useful for no-pad/space-pad checks
+        let timestamp = 1672876815_000_000i64; // 2023-01-05 00:00:15 UTC
+        let mut output = String::new();
+        let items = parse_format_tokens("%-m/%_d").unwrap();
+
+        format_with_locale(timestamp, &items, "en", None, &mut output);
+        assert_eq!(output, "1/ 5");
+    }
+
+    #[test]
+    fn test_braced_alignment() {
+        let timestamp = 1703516215_000_000i64; // 2023-12-25 14:56:55 UTC
+        let mut output = String::new();
+        let items = parse_format_tokens("{6>Y}|{4<j}|{4^G}").unwrap();
+
+        format_with_locale(timestamp, &items, "en", None, &mut output);
+        assert_eq!(output, "  2023|25  | 14 ");
+    }
+
+    #[test]
+    fn test_unterminated_brace_is_rejected() {
+        assert!(parse_format_tokens("{6>Y").is_err());
+    }
+
+    #[test]
+    fn test_parse_format_tokens_rejects_unknown_token() {
+        assert!(parse_format_tokens("Y-m-d Q").is_err());
+    }
+
+    #[test]
+    fn test_iso_week_tokens() {
+        // 2021-01-01 is a Friday in ISO week 53 of week-year 2020
+        let timestamp = 1609459200_000_000i64; // 2021-01-01 00:00:00 UTC
+        let mut output = String::new();
+        let items = parse_format_tokens("o-W-N").unwrap();
+
+        format_with_locale(timestamp, &items, "en", None, &mut output);
+        assert_eq!(output, "2020-53-5");
+    }
+
     #[test]
     fn test_humanize_duration_english() {
         let mut output = String::new();
@@ -0,0 +1,84 @@
+//! Locale-aware name and formatting lookups backed by ICU4X/CLDR data.
+//!
+//! This module only exists when the crate is built with the `icu` feature.
+//! It lets [`crate::formatting`] source month/day names, AM/PM markers and
+//! the decimal separator for arbitrary BCP-47 locales (`de`, `fr`, `ja`, ...)
+//! instead of the handful of hardcoded `en`/`pl` tables baked into the crate.
+//! Every lookup returns `Option` so callers can fall back to the static
+//! tables when a locale or data key isn't available.
+
+use icu_datetime::provider::calendar::GregorianDateSymbolsV1Marker;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_locid::Locale;
+use icu_provider::prelude::*;
+use std::str::FromStr;
+
+fn data_locale(locale_id: &str) -> Option<DataLocale> {
+    Locale::from_str(locale_id).ok().map(DataLocale::from)
+}
+
+/// Look up a localized month name (0-based index) via CLDR data.
+pub fn month_name(locale_id: &str, month_index0: usize, short: bool) -> Option<String> {
+    let locale = data_locale(locale_id)?;
+    let payload: DataPayload<GregorianDateSymbolsV1Marker> = icu_datetime::provider::Baked
+        .load(DataRequest { locale: &locale, metadata: Default::default() })
+        .ok()?
+        .take_payload()
+        .ok()?;
+
+    let symbols = payload.get();
+    let months = if short {
+        &symbols.months.format.abbreviated
+    } else {
+        &symbols.months.format.wide
+    };
+
+    months.0.get(month_index0).map(|s| s.to_string())
+}
+
+/// Look up a localized weekday name (0-based, Monday first) via CLDR data.
+pub fn day_name(locale_id: &str, day_index0_monday: usize, short: bool) -> Option<String> {
+    let locale = data_locale(locale_id)?;
+    let payload: DataPayload<GregorianDateSymbolsV1Marker> = icu_datetime::provider::Baked
+        .load(DataRequest { locale: &locale, metadata: Default::default() })
+        .ok()?
+        .take_payload()
+        .ok()?;
+
+    let symbols = payload.get();
+    let weekdays = if short {
+        &symbols.weekdays.format.abbreviated
+    } else {
+        &symbols.weekdays.format.wide
+    };
+
+    // CLDR weekday arrays are Sunday-first; Carbonic tokens are Monday-first.
+    let sunday_first_index = (day_index0_monday + 1) % 7;
+    weekdays.0.get(sunday_first_index).map(|s| s.to_string())
+}
+
+/// Look up the localized AM/PM marker for the given half of the day.
+pub fn am_pm(locale_id: &str, is_pm: bool) -> Option<String> {
+    let locale = data_locale(locale_id)?;
+    let payload: DataPayload<GregorianDateSymbolsV1Marker> = icu_datetime::provider::Baked
+        .load(DataRequest { locale: &locale, metadata: Default::default() })
+        .ok()?
+        .take_payload()
+        .ok()?;
+
+    let symbols = payload.get();
+    let index = if is_pm { 1 } else { 0 };
+    symbols.day_periods.format.abbreviated.0.get(index).map(|s| s.to_string())
+}
+
+/// Look up the localized decimal separator.
+pub fn decimal_separator(locale_id: &str) -> Option<char> {
+    let locale = data_locale(locale_id)?;
+    let payload: DataPayload<DecimalSymbolsV1Marker> = icu_decimal::provider::Baked
+        .load(DataRequest { locale: &locale, metadata: Default::default() })
+        .ok()?
+        .take_payload()
+        .ok()?;
+
+    payload.get().decimal_separator.chars().next()
+}
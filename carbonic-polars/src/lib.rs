@@ -5,8 +5,12 @@ pub mod expressions;
 pub mod formatting;
 pub mod parsing;
 pub mod business_days;
+pub mod duration;
 pub mod utils;
 
+#[cfg(feature = "icu")]
+pub mod icu_locale;
+
 #[global_allocator]
 static ALLOC: PolarsAllocator = PolarsAllocator::new();
 
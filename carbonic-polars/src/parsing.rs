@@ -1,8 +1,13 @@
 use chrono::{DateTime, NaiveDateTime, Utc};
 use pyo3_polars::export::polars_core::prelude::PolarsError;
 use std::collections::HashMap;
+use std::iter::Peekable;
+use std::str::Chars;
 use once_cell::sync::Lazy;
 
+use crate::formatting::{parse_format_tokens, FormatItem, Pad};
+use crate::utils::to_timestamp_micros;
+
 /// Map of Carbonic format tokens to chrono format patterns
 static CARBONIC_TOKEN_MAP: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
     let mut map = HashMap::new();
@@ -107,6 +112,197 @@ pub fn parse_iso_8601(input: &str) -> Result<DateTime<Utc>, PolarsError> {
     ))
 }
 
+/// Fields accumulated while scanning input against a format's token AST
+#[derive(Default)]
+struct ParsedFields {
+    year: Option<i32>,
+    month: Option<u32>,
+    day: Option<u32>,
+    hour24: Option<u32>,
+    hour12: Option<u32>,
+    minute: Option<u32>,
+    second: Option<u32>,
+    micro: Option<u32>,
+    is_pm: Option<bool>,
+}
+
+/// Parse `input` against a Carbonic `format` string (the same mini-language
+/// `format_with_locale` renders), returning a microsecond timestamp.
+///
+/// This is the reverse of [`crate::formatting::format_with_locale`]:
+/// numeric tokens consume fixed- or variable-width digits depending on
+/// their padding, name tokens (`F`/`M`/`l`/`D`) match against the
+/// locale's name tables, `A`/`a` disambiguate a 12-hour reading, and
+/// literal text (including `\\`-escapes) must match exactly.
+pub fn parse_with_locale(input: &str, format: &str, locale: &str) -> Result<i64, PolarsError> {
+    let items = parse_format_tokens(format)?;
+    let mut chars = input.chars().peekable();
+    let mut fields = ParsedFields::default();
+
+    for item in &items {
+        match item {
+            FormatItem::Literal(text) => consume_literal(&mut chars, text)?,
+
+            FormatItem::Year4 { pad } => {
+                fields.year = Some(consume_digits(&mut chars, 4, *pad == Pad::Zero)? as i32);
+            }
+            FormatItem::Year2 { pad } => {
+                let y2 = consume_digits(&mut chars, 2, *pad == Pad::Zero)? as i32;
+                // Same 69/00 pivot as strptime's %y: 00-68 -> 2000s, 69-99 -> 1900s
+                fields.year = Some(if y2 <= 68 { 2000 + y2 } else { 1900 + y2 });
+            }
+
+            FormatItem::Month { pad } => {
+                fields.month = Some(consume_digits(&mut chars, 2, *pad == Pad::Zero)?);
+            }
+            FormatItem::MonthName { short } => {
+                let candidates = crate::formatting::month_name_candidates(locale, *short);
+                fields.month = Some(consume_name(&mut chars, &candidates)? as u32 + 1);
+            }
+
+            FormatItem::Day { pad } => {
+                fields.day = Some(consume_digits(&mut chars, 2, *pad == Pad::Zero)?);
+            }
+            FormatItem::DayName { short } => {
+                // The weekday name carries no information beyond Y/m/d;
+                // just consume it so literal text around it still lines up.
+                let candidates = crate::formatting::day_name_candidates(locale, *short);
+                consume_name(&mut chars, &candidates)?;
+            }
+
+            FormatItem::Hour24 { pad } => {
+                fields.hour24 = Some(consume_digits(&mut chars, 2, *pad == Pad::Zero)?);
+            }
+            FormatItem::Hour12 { pad } => {
+                fields.hour12 = Some(consume_digits(&mut chars, 2, *pad == Pad::Zero)?);
+            }
+
+            FormatItem::Minute => fields.minute = Some(consume_digits(&mut chars, 2, true)?),
+            FormatItem::Second => fields.second = Some(consume_digits(&mut chars, 2, true)?),
+
+            FormatItem::Micros => fields.micro = Some(consume_digits(&mut chars, 6, true)?),
+            FormatItem::Millis => fields.micro = Some(consume_digits(&mut chars, 3, true)? * 1_000),
+
+            FormatItem::AmPm { .. } => {
+                let (am, pm) = crate::formatting::am_pm_candidates(locale);
+                fields.is_pm = Some(consume_name(&mut chars, &[am, pm])? == 1);
+            }
+
+            FormatItem::TzOffsetNoColon | FormatItem::TzOffsetColon | FormatItem::TzName
+            | FormatItem::Iso8601 | FormatItem::Rfc2822 | FormatItem::Aligned { .. }
+            | FormatItem::IsoWeek { .. } | FormatItem::IsoWeekYear { .. } | FormatItem::IsoWeekday => {
+                return Err(PolarsError::ComputeError(
+                    format!("Format token not supported by parse_with_locale in '{}'", format).into()
+                ));
+            }
+        }
+    }
+
+    if chars.next().is_some() {
+        return Err(PolarsError::ComputeError(
+            format!("Unexpected trailing input '{}' for format '{}'", input, format).into()
+        ));
+    }
+
+    let year = fields.year.ok_or_else(|| missing_component("year"))?;
+    let month = fields.month.ok_or_else(|| missing_component("month"))?;
+    let day = fields.day.ok_or_else(|| missing_component("day"))?;
+    let hour = resolve_hour(fields.hour24, fields.hour12, fields.is_pm)?;
+
+    to_timestamp_micros(
+        year,
+        month,
+        day,
+        hour,
+        fields.minute.unwrap_or(0),
+        fields.second.unwrap_or(0),
+        fields.micro.unwrap_or(0),
+    )
+}
+
+fn missing_component(name: &str) -> PolarsError {
+    PolarsError::ComputeError(format!("Missing required '{}' component while parsing datetime", name).into())
+}
+
+/// Reconcile a 24-hour and/or 12-hour reading (with optional AM/PM) into
+/// a single 0-23 hour, rejecting missing or inconsistent combinations.
+fn resolve_hour(hour24: Option<u32>, hour12: Option<u32>, is_pm: Option<bool>) -> Result<u32, PolarsError> {
+    match (hour24, hour12, is_pm) {
+        // A/a without a 12-hour token carries no extra information beyond
+        // the 24-hour reading; the 24-hour value already wins.
+        (Some(h), None, _) => Ok(h),
+        (None, Some(h), Some(is_pm)) => {
+            if h == 0 || h > 12 {
+                return Err(PolarsError::ComputeError(format!("12-hour value out of range: {}", h).into()));
+            }
+            Ok(match (h, is_pm) {
+                (12, false) => 0,
+                (12, true) => 12,
+                (h, false) => h,
+                (h, true) => h + 12,
+            })
+        }
+        (None, Some(_), None) => Err(PolarsError::ComputeError(
+            "A 12-hour token (h/g) requires an A/a marker in the format string".into()
+        )),
+        // No hour token at all (e.g. a date-only format like "Y-m-d")
+        (None, None, _) => Ok(0),
+        (Some(_), Some(_), _) => Err(PolarsError::ComputeError(
+            "Format string mixes 24-hour and 12-hour tokens".into()
+        )),
+    }
+}
+
+/// Consume `width` digits, requiring exactly that many when `exact` is
+/// set (zero-padded tokens), or 1..=width greedily otherwise.
+fn consume_digits(chars: &mut Peekable<Chars>, width: usize, exact: bool) -> Result<u32, PolarsError> {
+    let mut digits = String::new();
+    while digits.len() < width && matches!(chars.peek(), Some(c) if c.is_ascii_digit()) {
+        digits.push(chars.next().unwrap());
+    }
+
+    if digits.is_empty() || (exact && digits.len() != width) {
+        return Err(PolarsError::ComputeError("Expected a numeric field while parsing datetime".into()));
+    }
+
+    digits.parse().map_err(|_| PolarsError::ComputeError("Invalid numeric field while parsing datetime".into()))
+}
+
+/// Consume literal text, requiring an exact character-for-character match
+fn consume_literal(chars: &mut Peekable<Chars>, text: &str) -> Result<(), PolarsError> {
+    for expected in text.chars() {
+        match chars.next() {
+            Some(actual) if actual == expected => {}
+            _ => return Err(PolarsError::ComputeError(
+                format!("Expected literal '{}' while parsing datetime", text).into()
+            )),
+        }
+    }
+    Ok(())
+}
+
+/// Consume the longest matching candidate name (case-insensitive),
+/// returning its index.
+fn consume_name(chars: &mut Peekable<Chars>, candidates: &[String]) -> Result<usize, PolarsError> {
+    let remaining: String = chars.clone().collect();
+    let remaining_lower = remaining.to_lowercase();
+
+    let best = candidates.iter()
+        .enumerate()
+        .filter(|(_, name)| remaining_lower.starts_with(&name.to_lowercase()))
+        .max_by_key(|(_, name)| name.chars().count());
+
+    match best {
+        Some((idx, name)) => {
+            for _ in 0..name.chars().count() {
+                chars.next();
+            }
+            Ok(idx)
+        }
+        None => Err(PolarsError::ComputeError("Expected a localized name while parsing datetime".into())),
+    }
+}
+
 /// Convert Carbonic format tokens to chrono format
 fn convert_carbonic_to_chrono_format(format: &str) -> Result<String, PolarsError> {
     let mut result = format.to_string();
@@ -194,6 +390,46 @@ mod tests {
         assert_eq!(dt.day(), 25);
     }
 
+    #[test]
+    fn test_parse_with_locale_numeric() {
+        let ts = parse_with_locale("2023-12-25 14:30:15", "Y-m-d H:i:s", "en").unwrap();
+        assert_eq!(
+            ts,
+            to_timestamp_micros(2023, 12, 25, 14, 30, 15, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_with_locale_month_name_and_12h() {
+        let ts = parse_with_locale("December 25, 2023 02:30 PM", "F j, Y h:i A", "en").unwrap();
+        assert_eq!(
+            ts,
+            to_timestamp_micros(2023, 12, 25, 14, 30, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_with_locale_polish_month_name() {
+        let ts = parse_with_locale("25 grudnia 2023", "j M Y", "pl");
+        // "grudnia" (genitive) isn't in the nominative month table; the
+        // parser only understands the nominative short/long forms used
+        // by format_with_locale ("grudzień"/"gru").
+        assert!(ts.is_err());
+
+        let ts = parse_with_locale("25 gru 2023", "j M Y", "pl").unwrap();
+        assert_eq!(ts, to_timestamp_micros(2023, 12, 25, 0, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_parse_with_locale_rejects_missing_component() {
+        assert!(parse_with_locale("2023-12", "Y-m", "en").is_err());
+    }
+
+    #[test]
+    fn test_parse_with_locale_rejects_trailing_input() {
+        assert!(parse_with_locale("2023-12-25extra", "Y-m-d", "en").is_err());
+    }
+
     #[test]
     fn test_parse_iso_8601() {
         let inputs = [
@@ -1,6 +1,6 @@
 /// Utility functions for the Carbonic Polars plugin
 
-use chrono::{DateTime, Utc, NaiveDate};
+use chrono::{DateTime, Datelike, Duration, Utc, NaiveDate};
 use pyo3_polars::export::polars_core::prelude::PolarsError;
 
 /// Convert various datetime representations to microseconds since Unix epoch
@@ -21,34 +21,45 @@ pub fn from_timestamp_micros(timestamp_micros: i64) -> Result<DateTime<Utc>, Pol
         .ok_or_else(|| PolarsError::ComputeError("Invalid timestamp".into()))
 }
 
-/// Validate that a format string contains only supported tokens
-pub fn validate_format_string(format: &str) -> Result<(), PolarsError> {
-    let supported_tokens = [
-        "Y", "y",           // Year
-        "m", "n", "F", "M", // Month
-        "d", "j", "l", "D", // Day
-        "H", "G", "h", "g", // Hour
-        "i", "s",           // Minute/Second
-        "u", "v",           // Microsecond/Millisecond
-        "A", "a",           // AM/PM
-        "T", "P", "O",      // Timezone
-        "c", "r",           // Special formats
-    ];
-
-    // Simple validation - check for unknown single-char tokens
-    // This is a basic check; more sophisticated validation could be added
-    for ch in format.chars() {
-        if ch.is_alphabetic() && !supported_tokens.contains(&ch.to_string().as_str()) {
-            // Allow common literal characters and escapes
-            if !"\\-/:.,; ()[]{}".contains(ch) {
-                return Err(PolarsError::ComputeError(
-                    format!("Unsupported format token: '{}'", ch).into()
-                ));
-            }
-        }
+/// Convert an ISO-8601 week-date (week-year, week number, weekday) to
+/// microseconds since Unix epoch.
+///
+/// ISO week 1 is the week containing the year's first Thursday, so its
+/// Monday can fall in late December of the previous calendar year. Finding
+/// it from Jan 4th (which is always in week 1) and walking forward
+/// `(week - 1) * 7 + (weekday - 1)` days handles that without reimplementing
+/// the ISO week rules by hand. The result is rejected if it lands outside
+/// the requested week-year, which only happens when `week` doesn't exist
+/// for that year (e.g. week 53 in a year with only 52).
+pub fn to_timestamp_micros_isoweek(iso_year: i32, week: u32, weekday: u32, hour: u32, minute: u32, second: u32, microsecond: u32) -> Result<i64, PolarsError> {
+    let invalid = || PolarsError::ComputeError("Invalid ISO week-date components".into());
+
+    if !(1..=53).contains(&week) || !(1..=7).contains(&weekday) {
+        return Err(invalid());
+    }
+
+    let jan4 = NaiveDate::from_ymd_opt(iso_year, 1, 4).ok_or_else(invalid)?;
+    let week1_monday = jan4 - Duration::days(jan4.weekday().num_days_from_monday() as i64);
+    let date = week1_monday + Duration::days((week as i64 - 1) * 7 + (weekday as i64 - 1));
+
+    if date.iso_week().year() != iso_year {
+        return Err(PolarsError::ComputeError(
+            format!("Week {} does not exist in ISO week-year {}", week, iso_year).into()
+        ));
     }
 
-    Ok(())
+    let naive = date.and_hms_micro_opt(hour, minute, second, microsecond)
+        .ok_or_else(|| PolarsError::ComputeError("Invalid datetime components".into()))?;
+
+    Ok(naive.and_utc().timestamp_micros())
+}
+
+/// Validate that a format string contains only supported tokens
+///
+/// Delegates to [`crate::formatting::parse_format_tokens`] so validation
+/// and parsing can never drift apart.
+pub fn validate_format_string(format: &str) -> Result<(), PolarsError> {
+    crate::formatting::parse_format_tokens(format).map(|_| ())
 }
 
 /// Helper to safely handle null values in Series operations
@@ -91,6 +102,21 @@ mod tests {
         assert!(validate_format_string("Y-m-d Q").is_err());
     }
 
+    #[test]
+    fn test_isoweek_construction_roundtrip() {
+        // ISO week-date 2020-W53-5 is 2021-01-01
+        let timestamp = to_timestamp_micros_isoweek(2020, 53, 5, 0, 0, 0, 0).unwrap();
+        let datetime = from_timestamp_micros(timestamp).unwrap();
+
+        assert_eq!((datetime.year(), datetime.month(), datetime.day()), (2021, 1, 1));
+    }
+
+    #[test]
+    fn test_isoweek_construction_rejects_week_outside_year() {
+        // 2021 only has 52 ISO weeks
+        assert!(to_timestamp_micros_isoweek(2021, 53, 1, 0, 0, 0, 0).is_err());
+    }
+
     #[test]
     fn test_null_propagation() {
         let result = handle_null_propagation(Some(42), |x| Some(x * 2));